@@ -0,0 +1,243 @@
+//! Non-blocking variants of the driver and the auto-ranging wrapper, built on
+//! `embedded-hal-async` instead of `embedded-hal`'s blocking `i2c` traits.
+//!
+//! These mirror [`crate::Veml6040`] and [`crate::wrapper::AutoVeml6040`],
+//! but every register access is an `async fn` and the auto-ranging
+//! wrapper takes an injected [`DelayNs`] instead of calling
+//! `std::thread::sleep`, so it can run on bare-metal executors such as
+//! Embassy or RTIC. `Veml6040Async` has no `destroy`, and
+//! `AutoVeml6040Async` has no `start_measurement`/`is_ready`/`collect`
+//! split: its measurement methods await the delay themselves.
+
+use crate::{
+    integration_time::IntegrationTime,
+    wrapper::{
+        ranging_decision, AbsoluteMeasurementChannels, AbsoluteMeasurementError, DarkOffsets,
+        RangingThresholds,
+    },
+    BitFlags, Error, MeasurementMode, Register, DEVICE_ADDRESS,
+};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::I2c;
+use log::*;
+
+/// Asynchronous counterpart of [`crate::Veml6040`].
+pub struct Veml6040Async<I2C> {
+    i2c: I2C,
+    config: u8,
+}
+
+impl<I2C, E> Veml6040Async<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Creates a new instance of the device.
+    pub fn new(i2c: I2C) -> Self {
+        Veml6040Async { i2c, config: 0 }
+    }
+
+    /// Enable the sensor.
+    pub async fn enable(&mut self) -> Result<(), Error<E>> {
+        let config = self.config;
+        self.write_config(config & !BitFlags::SHUTDOWN).await
+    }
+
+    /// Disable the sensor (shutdown).
+    pub async fn disable(&mut self) -> Result<(), Error<E>> {
+        let config = self.config;
+        self.write_config(config | BitFlags::SHUTDOWN).await
+    }
+
+    /// Set the integration time.
+    pub async fn set_integration_time(&mut self, it: IntegrationTime) -> Result<(), Error<E>> {
+        const IT_BITS: u8 = 0b0111_0000;
+        let config = self.config & !IT_BITS;
+        self.write_config(config | it.bit_pattern()).await
+    }
+
+    /// Set the measurement mode: `Auto`/`Manual`.
+    pub async fn set_measurement_mode(&mut self, mode: MeasurementMode) -> Result<(), Error<E>> {
+        let config = self.config;
+        match mode {
+            MeasurementMode::Auto => self.write_config(config & !BitFlags::AF).await,
+            MeasurementMode::Manual => self.write_config(config | BitFlags::AF).await,
+        }
+    }
+
+    /// Trigger a measurement when on `Manual` measurement mode.
+    ///
+    /// This is not necessary on `Auto` measurement mode.
+    pub async fn trigger_measurement(&mut self) -> Result<(), Error<E>> {
+        // This bit is not stored to avoid unintended triggers.
+        self.i2c
+            .write(
+                DEVICE_ADDRESS,
+                &[Register::CONFIG, self.config | BitFlags::TRIG, 0],
+            )
+            .await
+            .map_err(Error::I2C)
+    }
+
+    async fn write_config(&mut self, config: u8) -> Result<(), Error<E>> {
+        self.i2c
+            .write(DEVICE_ADDRESS, &[Register::CONFIG, config, 0])
+            .await
+            .map_err(Error::I2C)?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// Read the red channel measurement data.
+    pub async fn read_red_channel(&mut self) -> Result<u16, Error<E>> {
+        self.read_channel(Register::R_DATA).await
+    }
+
+    /// Read the green channel measurement data.
+    pub async fn read_green_channel(&mut self) -> Result<u16, Error<E>> {
+        self.read_channel(Register::G_DATA).await
+    }
+
+    /// Read the blue channel measurement data.
+    pub async fn read_blue_channel(&mut self) -> Result<u16, Error<E>> {
+        self.read_channel(Register::B_DATA).await
+    }
+
+    /// Read the white channel measurement data.
+    pub async fn read_white_channel(&mut self) -> Result<u16, Error<E>> {
+        self.read_channel(Register::W_DATA).await
+    }
+
+    /// Read the measurement data of all channels at once.
+    pub async fn read_all_channels(&mut self) -> Result<crate::AllChannelMeasurement, Error<E>> {
+        Ok(crate::AllChannelMeasurement {
+            red: self.read_red_channel().await?,
+            green: self.read_green_channel().await?,
+            blue: self.read_blue_channel().await?,
+            white: self.read_white_channel().await?,
+        })
+    }
+
+    async fn read_channel(&mut self, first_register: u8) -> Result<u16, Error<E>> {
+        let mut data = [0; 2];
+        self.i2c
+            .write_read(DEVICE_ADDRESS, &[first_register], &mut data)
+            .await
+            .map_err(Error::I2C)
+            .and(Ok(u16::from(data[1]) << 8 | u16::from(data[0])))
+    }
+}
+
+fn error_mapper<E>(e: Error<E>) -> AbsoluteMeasurementError<E> {
+    AbsoluteMeasurementError::ReadErr(e)
+}
+
+/// Asynchronous counterpart of [`crate::wrapper::AutoVeml6040`].
+///
+/// Instead of blocking on `std::thread::sleep` while the sensor integrates,
+/// [`Self::read_absolute_once`] awaits an injected [`DelayNs`], so the
+/// executor can run other tasks during the wait.
+pub struct AutoVeml6040Async<I2C, E>
+where
+    I2C: I2c<Error = E>,
+{
+    sensor: Veml6040Async<I2C>,
+    integration_time: IntegrationTime,
+    thresholds: RangingThresholds,
+    dark_offsets: DarkOffsets,
+}
+
+impl<I2C, E> AutoVeml6040Async<I2C, E>
+where
+    I2C: I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Constructs a new instance of the wrapper, containing a sensor that
+    /// will be initialized and configured to be used for measurements,
+    /// using the default ranging thresholds and no dark-offset
+    /// compensation.
+    ///
+    /// Fails if the initial `enable`/`set_integration_time`/
+    /// `set_measurement_mode` writes can't reach the sensor.
+    pub async fn new(i2c: I2C) -> Result<Self, AbsoluteMeasurementError<E>> {
+        Self::with_config(i2c, RangingThresholds::default(), DarkOffsets::default()).await
+    }
+
+    /// Like [`Self::new`], but with custom ranging thresholds and
+    /// per-channel dark-count offsets, for tuning auto-ranging behavior to
+    /// a given optical setup.
+    pub async fn with_config(
+        i2c: I2C,
+        thresholds: RangingThresholds,
+        dark_offsets: DarkOffsets,
+    ) -> Result<Self, AbsoluteMeasurementError<E>> {
+        let mut ret = AutoVeml6040Async {
+            sensor: Veml6040Async::new(i2c),
+            integration_time: IntegrationTime::_160ms,
+            thresholds,
+            dark_offsets,
+        };
+
+        ret.sensor.enable().await.map_err(error_mapper)?;
+        ret.sensor
+            .set_integration_time(ret.integration_time)
+            .await
+            .map_err(error_mapper)?;
+        ret.sensor
+            .set_measurement_mode(MeasurementMode::Manual)
+            .await
+            .map_err(error_mapper)?;
+
+        Ok(ret)
+    }
+
+    /// Makes a single reading, which may either succeed or return an error.
+    /// If possible, the integration time is adjusted after the measurement,
+    /// so that future measurements may have more success than the current
+    /// one.
+    ///
+    /// `delay` is awaited for the integration time's recommended waiting
+    /// period between triggering and reading the measurement.
+    pub async fn read_absolute_once<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
+        self.sensor
+            .trigger_measurement()
+            .await
+            .map_err(error_mapper)?;
+        let wait_time = self.integration_time.waiting_time_millis();
+        delay.delay_ms(wait_time as u32).await;
+        let reading = self.sensor.read_all_channels().await.map_err(error_mapper)?;
+        let (new_integration_time, result) =
+            ranging_decision(&reading, self.integration_time, &self.thresholds, &self.dark_offsets);
+
+        if new_integration_time != self.integration_time {
+            self.integration_time = new_integration_time;
+            debug!(target: "Wrapper", "Switching to integration time {:?}...", self.integration_time.millis());
+            self.sensor
+                .set_integration_time(self.integration_time)
+                .await
+                .map_err(error_mapper)?;
+        }
+
+        result
+    }
+
+    /// Make measurements, and retry as long as the integration time can be
+    /// optimized to get a valid measurement. Will return either a valid,
+    /// absolute measurement, or an error indicating the reason.
+    pub async fn read_absolute_retry<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
+        loop {
+            let result = self.read_absolute_once(delay).await;
+            match result {
+                Err(AbsoluteMeasurementError::TooDarkAbsolute)
+                | Err(AbsoluteMeasurementError::TooBrightAbsolute)
+                | Ok(_) => return result,
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Correlated color temperature (CCT) derived from channel measurements.
+//!
+//! These are free functions over raw red/green/blue values (rather than
+//! `AbsoluteMeasurementChannels` methods) so they can also be applied to a
+//! raw [`crate::AllChannelMeasurement`] once scaled by
+//! [`crate::IntegrationTime::sensitivity()`].
+
+/// Computes the VEML6040 application note's color-temperature index,
+/// `CCTi = (R - B) / G`.
+///
+/// Returns `None` when `green` is zero, since the index is undefined there.
+pub fn cct_index(red: f32, green: f32, blue: f32) -> Option<f32> {
+    if green == 0.0 {
+        None
+    } else {
+        Some((red - blue) / green)
+    }
+}
+
+/// Converts red/green/blue channel values into a correlated color
+/// temperature in kelvin, per the VEML6040 application note's
+/// `CCT = 4278.6 * CCTi.powf(-1.2855)`.
+///
+/// Returns `None` when the color-temperature index is undefined or not
+/// strictly positive, since `powf` would otherwise yield `NaN` for a zero
+/// or negative base.
+pub fn cct_kelvin(red: f32, green: f32, blue: f32) -> Option<f32> {
+    let index = cct_index(red, green, blue)?;
+    if index <= 0.0 {
+        None
+    } else {
+        Some(4278.6 * libm::powf(index, -1.2855))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cct_index_none_when_green_is_zero() {
+        assert_eq!(cct_index(100.0, 0.0, 50.0), None);
+    }
+
+    #[test]
+    fn cct_kelvin_none_when_green_is_zero() {
+        assert_eq!(cct_kelvin(100.0, 0.0, 50.0), None);
+    }
+
+    #[test]
+    fn cct_kelvin_none_for_negative_index() {
+        // red < blue makes (red - blue) / green negative.
+        assert_eq!(cct_kelvin(10.0, 100.0, 50.0), None);
+    }
+
+    #[test]
+    fn cct_kelvin_none_for_zero_index() {
+        // red == blue makes the index exactly zero.
+        assert_eq!(cct_kelvin(50.0, 100.0, 50.0), None);
+    }
+
+    #[test]
+    fn cct_kelvin_matches_application_note_formula() {
+        let kelvin = cct_kelvin(120.0, 100.0, 40.0).unwrap();
+        let expected = 4278.6 * libm::powf(0.8, -1.2855);
+        assert!((kelvin - expected).abs() < 0.001);
+    }
+}
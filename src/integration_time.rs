@@ -34,7 +34,27 @@ impl IntegrationTime {
      * The recommended waiting time for this integration time, in milliseconds.
      */
     pub fn waiting_time_millis(&self) -> i32 {
-        return self.millis() + 40;
+        self.millis() + 40
+    }
+
+    /**
+     * The duration of the integration time, as a unit-checked [`uom::si::f32::Time`].
+     *
+     * Unlike [`Self::millis`], the result can't accidentally be passed to an
+     * API expecting microseconds or seconds without a compile error.
+     */
+    #[cfg(feature = "uom")]
+    pub fn duration(&self) -> uom::si::f32::Time {
+        uom::si::f32::Time::new::<uom::si::time::millisecond>(self.millis() as f32)
+    }
+
+    /**
+     * The recommended waiting time for this integration time, as a
+     * unit-checked [`uom::si::f32::Time`]. See [`Self::duration`].
+     */
+    #[cfg(feature = "uom")]
+    pub fn waiting_duration(&self) -> uom::si::f32::Time {
+        uom::si::f32::Time::new::<uom::si::time::millisecond>(self.waiting_time_millis() as f32)
     }
 
     /**
@@ -68,4 +88,66 @@ impl IntegrationTime {
         }
     }
 
+    fn index(&self) -> usize {
+        ORDER
+            .iter()
+            .position(|it| it == self)
+            .expect("every IntegrationTime variant is in ORDER")
+    }
+
+    /**
+     * The next longer integration time in the table, or the same value if
+     * this is already the longest one available. Callers detect "no longer
+     * time available" by comparing the result to `self`.
+     */
+    pub fn get_next(&self) -> IntegrationTime {
+        ORDER[(self.index() + 1).min(ORDER.len() - 1)]
+    }
+
+    /**
+     * The next shorter integration time in the table, or the same value if
+     * this is already the shortest one available. Callers detect "no
+     * shorter time available" by comparing the result to `self`.
+     */
+    pub fn get_prev(&self) -> IntegrationTime {
+        ORDER[self.index().saturating_sub(1)]
+    }
+}
+
+/// All integration times, ordered from shortest to longest. `get_next`/
+/// `get_prev` step through this table by index.
+const ORDER: [IntegrationTime; 6] = [
+    IntegrationTime::_40ms,
+    IntegrationTime::_80ms,
+    IntegrationTime::_160ms,
+    IntegrationTime::_320ms,
+    IntegrationTime::_640ms,
+    IntegrationTime::_1280ms,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_next_steps_through_the_table() {
+        assert_eq!(IntegrationTime::_40ms.get_next(), IntegrationTime::_80ms);
+        assert_eq!(IntegrationTime::_640ms.get_next(), IntegrationTime::_1280ms);
+    }
+
+    #[test]
+    fn get_next_clamps_at_the_longest_entry() {
+        assert_eq!(IntegrationTime::_1280ms.get_next(), IntegrationTime::_1280ms);
+    }
+
+    #[test]
+    fn get_prev_steps_through_the_table() {
+        assert_eq!(IntegrationTime::_1280ms.get_prev(), IntegrationTime::_640ms);
+        assert_eq!(IntegrationTime::_80ms.get_prev(), IntegrationTime::_40ms);
+    }
+
+    #[test]
+    fn get_prev_clamps_at_the_shortest_entry() {
+        assert_eq!(IntegrationTime::_40ms.get_prev(), IntegrationTime::_40ms);
+    }
 }
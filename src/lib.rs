@@ -0,0 +1,91 @@
+//! This is a platform agnostic Rust driver for the VEML6040 RGBW color light
+//! sensor, based on the [`embedded-hal`] traits.
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+#![deny(unsafe_code)]
+#![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod color;
+mod configuration;
+mod integration_time;
+mod reading;
+mod wrapper;
+
+#[cfg(feature = "async")]
+mod asynch;
+
+pub use crate::integration_time::IntegrationTime;
+pub use crate::wrapper::{
+    AbsoluteMeasurementChannels, AbsoluteMeasurementError, AutoVeml6040, DarkOffsets, Lux,
+    RangingThresholds,
+};
+
+#[cfg(feature = "async")]
+pub use crate::asynch::{AutoVeml6040Async, Veml6040Async};
+
+const DEVICE_ADDRESS: u8 = 0x10;
+
+struct Register;
+impl Register {
+    const CONFIG: u8 = 0x00;
+    const R_DATA: u8 = 0x08;
+    const G_DATA: u8 = 0x09;
+    const B_DATA: u8 = 0x0A;
+    const W_DATA: u8 = 0x0B;
+}
+
+struct BitFlags;
+impl BitFlags {
+    const SHUTDOWN: u8 = 0b0000_0001;
+    const AF: u8 = 0b0000_0010;
+    const TRIG: u8 = 0b0000_0100;
+}
+
+/// Measurement mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeasurementMode {
+    /// Automatic mode.
+    Auto,
+    /// Manual mode.
+    Manual,
+}
+
+/// All possible errors in this crate.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// I2C bus error.
+    I2C(E),
+}
+
+/// Result of measuring all channels, in raw sensor counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AllChannelMeasurement {
+    /// Red channel measurement.
+    pub red: u16,
+    /// Green channel measurement.
+    pub green: u16,
+    /// Blue channel measurement.
+    pub blue: u16,
+    /// White channel measurement.
+    pub white: u16,
+}
+
+/// VEML6040 device driver.
+#[derive(Debug)]
+pub struct Veml6040<I2C> {
+    i2c: I2C,
+    config: u8,
+}
+
+impl<I2C> Veml6040<I2C> {
+    /// Create a new instance of the device.
+    pub fn new(i2c: I2C) -> Self {
+        Veml6040 { i2c, config: 0 }
+    }
+
+    /// Destroy the driver instance, returning the I2C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+}
@@ -15,6 +15,43 @@ pub struct AbsoluteMeasurementChannels {
     pub white: f32,
 }
 
+impl AbsoluteMeasurementChannels {
+    /// The correlated color temperature in kelvin, derived from the
+    /// red/green/blue channels via [`crate::color::cct_kelvin`].
+    ///
+    /// Returns `None` in near-darkness, where the underlying
+    /// color-temperature index is undefined or non-positive.
+    pub fn cct_kelvin(&self) -> Option<f32> {
+        crate::color::cct_kelvin(self.red, self.green, self.blue)
+    }
+
+    /// The ambient light level in lux.
+    ///
+    /// This is already what the green channel reports, scaled by the
+    /// current integration time's sensitivity.
+    ///
+    /// This stays [`Lux`] rather than `uom::si::f32::Luminance`, including
+    /// under the `uom` feature: `uom` has no `Illuminance`/lux quantity,
+    /// only `Luminance` (candela per square meter), which is a different
+    /// physical unit. Wrapping a lux value in `Luminance` would type-check
+    /// but let it be silently combined with genuine luminance values,
+    /// which is worse than a newtype that at least doesn't claim a unit
+    /// it isn't.
+    pub fn lux(&self) -> Lux {
+        Lux(self.green)
+    }
+}
+
+/// An ambient light level in lux, as returned by
+/// [`AbsoluteMeasurementChannels::lux`].
+///
+/// This is a minimal newtype rather than a raw `f32` so a lux value can't
+/// be silently passed where a raw channel count or another physical
+/// quantity is expected. See [`AbsoluteMeasurementChannels::lux`] for why
+/// this isn't a `uom` quantity.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Lux(pub f32);
+
 /// Different kinds of errors that may occur while getting an absolute measurement.
 #[derive(Debug)]
 pub enum AbsoluteMeasurementError<E> {
@@ -34,7 +71,7 @@ pub enum AbsoluteMeasurementError<E> {
     TooBrightAbsolute,
 }
 
-/// A wrapper around a sensor that offers absolute measurements and 
+/// A wrapper around a sensor that offers absolute measurements and
 /// automatic selection of a suitable integration time.
 pub struct AutoVeml6040<I2C, E>
 where
@@ -42,15 +79,135 @@ where
 {
     sensor: Veml6040<I2C>,
     integration_time: IntegrationTime,
+    thresholds: RangingThresholds,
+    dark_offsets: DarkOffsets,
 }
 
-const DARK_THRESHOLD_SOFT: u16 = 500;
-const DARK_THRESHOLD_HARD: u16 = 10;
-const BRIGHT_THRESHOLD_SOFT: u16 = 20_000;
-const BRIGHT_THRESHOLD_HARD: u16 = 64_000;
+pub(crate) const DARK_THRESHOLD_SOFT: u16 = 500;
+pub(crate) const DARK_THRESHOLD_HARD: u16 = 10;
+pub(crate) const BRIGHT_THRESHOLD_SOFT: u16 = 20_000;
+pub(crate) const BRIGHT_THRESHOLD_HARD: u16 = 64_000;
+
+/// The green-channel thresholds that drive auto-ranging and error reporting.
+///
+/// `*_soft` thresholds make the wrapper try a longer/shorter integration
+/// time on the next measurement; crossing a `*_hard` threshold makes the
+/// measurement fail, with a `*Relative` error while a longer/shorter time
+/// is still left to try and a `*Absolute` error once there is none. Callers
+/// providing custom thresholds must keep `dark_hard <= dark_soft` and
+/// `bright_soft <= bright_hard`, or the hard threshold can fire before the
+/// wrapper ever attempts the corresponding soft-threshold adjustment.
+#[derive(Debug, Clone, Copy)]
+pub struct RangingThresholds {
+    /// Below this, the wrapper tries a longer integration time.
+    pub dark_soft: u16,
+    /// Below this, the measurement is reported as too dark (as
+    /// `TooDarkAbsolute` at the longest integration time).
+    pub dark_hard: u16,
+    /// Above this, the wrapper tries a shorter integration time.
+    pub bright_soft: u16,
+    /// Above this, the measurement is reported as too bright (as
+    /// `TooBrightAbsolute` at the shortest integration time).
+    pub bright_hard: u16,
+}
+
+impl Default for RangingThresholds {
+    fn default() -> Self {
+        RangingThresholds {
+            dark_soft: DARK_THRESHOLD_SOFT,
+            dark_hard: DARK_THRESHOLD_HARD,
+            bright_soft: BRIGHT_THRESHOLD_SOFT,
+            bright_hard: BRIGHT_THRESHOLD_HARD,
+        }
+    }
+}
+
+/// Per-channel dark-count offsets, subtracted (with saturation at zero)
+/// from each raw reading before lux/sensitivity scaling, to compensate for
+/// the sensor's intrinsic dark offset.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DarkOffsets {
+    /// Offset subtracted from the raw red channel count.
+    pub red: u16,
+    /// Offset subtracted from the raw green channel count.
+    pub green: u16,
+    /// Offset subtracted from the raw blue channel count.
+    pub blue: u16,
+    /// Offset subtracted from the raw white channel count.
+    pub white: u16,
+}
+
+/// Subtracts `offset` from `raw`, saturating at zero instead of
+/// underflowing.
+pub(crate) fn reduce(raw: u16, offset: u16) -> u16 {
+    raw.saturating_sub(offset)
+}
+
+/// Returns whether `elapsed_ms` milliseconds is enough for `integration_time`
+/// to have settled.
+pub(crate) fn is_ready(elapsed_ms: i32, integration_time: IntegrationTime) -> bool {
+    elapsed_ms >= integration_time.waiting_time_millis()
+}
 
 fn error_mapper<E>(e: Error<E>) -> AbsoluteMeasurementError<E> {
-    return AbsoluteMeasurementError::ReadErr(e);
+    AbsoluteMeasurementError::ReadErr(e)
+}
+
+/// The non-I/O half of turning a raw [`crate::AllChannelMeasurement`] into
+/// an absolute measurement: decides whether the integration time should
+/// change for the next attempt, and builds the final `Ok`/`Err`.
+///
+/// Shared by [`AutoVeml6040::collect`] and
+/// [`crate::asynch::AutoVeml6040Async::read_absolute_once`] so the two
+/// can't drift apart on auto-ranging behavior.
+///
+/// Returns the integration time to use from now on, and the result for
+/// this measurement.
+pub(crate) fn ranging_decision<E>(
+    reading: &crate::AllChannelMeasurement,
+    integration_time: IntegrationTime,
+    thresholds: &RangingThresholds,
+    dark_offsets: &DarkOffsets,
+) -> (IntegrationTime, Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>>) {
+    let green = reduce(reading.green, dark_offsets.green);
+
+    let new_integration_time = if green < thresholds.dark_soft {
+        integration_time.get_next()
+    } else if green > thresholds.bright_soft {
+        integration_time.get_prev()
+    } else {
+        integration_time
+    };
+    // get_next()/get_prev() return the same value once the table is
+    // exhausted, which is how "no longer/shorter time available" is
+    // detected below.
+    let could_adjust = new_integration_time != integration_time;
+
+    // save sensitivity before potentially changing to another integration time
+    let sensitivity = integration_time.sensitivity();
+
+    let result = if green < thresholds.dark_hard {
+        if could_adjust {
+            Err(AbsoluteMeasurementError::TooDarkRelative)
+        } else {
+            Err(AbsoluteMeasurementError::TooDarkAbsolute)
+        }
+    } else if green > thresholds.bright_hard {
+        if could_adjust {
+            Err(AbsoluteMeasurementError::TooBrightRelative)
+        } else {
+            Err(AbsoluteMeasurementError::TooBrightAbsolute)
+        }
+    } else {
+        Ok(AbsoluteMeasurementChannels {
+            red: sensitivity * (reduce(reading.red, dark_offsets.red) as f32),
+            green: sensitivity * (green as f32),
+            blue: sensitivity * (reduce(reading.blue, dark_offsets.blue) as f32),
+            white: sensitivity * (reduce(reading.white, dark_offsets.white) as f32),
+        })
+    };
+
+    (new_integration_time, result)
 }
 
 impl<I2C, E> AutoVeml6040<I2C, E>
@@ -59,77 +216,96 @@ where
     E: core::fmt::Debug,
 {
     /// Constructs a new instance of the wrapper, containing a sensor that will
-    /// be initialized and configured to be used for measurements.
-    pub fn new(i2c: I2C) -> Self {
+    /// be initialized and configured to be used for measurements, using the
+    /// default ranging thresholds and no dark-offset compensation.
+    ///
+    /// Fails if the initial `enable`/`set_integration_time`/
+    /// `set_measurement_mode` writes can't reach the sensor.
+    pub fn new(i2c: I2C) -> Result<Self, AbsoluteMeasurementError<E>> {
+        Self::with_config(i2c, RangingThresholds::default(), DarkOffsets::default())
+    }
+
+    /// Like [`Self::new`], but with custom ranging thresholds and per-channel
+    /// dark-count offsets, for tuning auto-ranging behavior to a given
+    /// optical setup.
+    pub fn with_config(
+        i2c: I2C,
+        thresholds: RangingThresholds,
+        dark_offsets: DarkOffsets,
+    ) -> Result<Self, AbsoluteMeasurementError<E>> {
         let mut ret = AutoVeml6040 {
             sensor: Veml6040::new(i2c),
             integration_time: IntegrationTime::_160ms,
+            thresholds,
+            dark_offsets,
         };
 
-        ret.sensor.enable().map_err(error_mapper).unwrap();
-        ret.sensor.set_integration_time(ret.integration_time).unwrap();
-        ret.sensor.set_measurement_mode(MeasurementMode::Manual).unwrap();
+        ret.sensor.enable().map_err(error_mapper)?;
+        ret.sensor
+            .set_integration_time(ret.integration_time)
+            .map_err(error_mapper)?;
+        ret.sensor
+            .set_measurement_mode(MeasurementMode::Manual)
+            .map_err(error_mapper)?;
 
-        return ret;
+        Ok(ret)
     }
 
-    /// Makes a single reading, which may either succeed or return an error.
-    /// If possible, the integration time is adjusted after the measuement,
-    /// so that future measurements may have more success then the current one.
-    pub fn read_absolute_once(&mut self) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
+    /// Triggers a measurement and returns the settling time, in milliseconds,
+    /// that must elapse before [`Self::collect`] may be called.
+    ///
+    /// This is the non-blocking half of [`Self::read_absolute_once`], for
+    /// callers that can't afford to block the current thread while the
+    /// sensor integrates, e.g. on a bare-metal executor driven by an
+    /// RTIC/Embassy timer.
+    pub fn start_measurement(&mut self) -> Result<i32, AbsoluteMeasurementError<E>> {
         self.sensor.trigger_measurement().map_err(error_mapper)?;
-        let wait_time = self.integration_time.waiting_time_millis();
-        std::thread::sleep(core::time::Duration::from_millis(wait_time as u64));
-        let reading = self.sensor.read_all_channels().map_err(error_mapper)?;
-        let green = reading.green;
-
-        let new_integration_time_opt = {
-            if green < DARK_THRESHOLD_SOFT {
-                self.integration_time.longer()
-            } else if green > BRIGHT_THRESHOLD_SOFT {
-                self.integration_time.shorter()
-            } else {
-                None
-            }
-        };
+        Ok(self.integration_time.waiting_time_millis())
+    }
+
+    /// Returns whether `elapsed_ms` milliseconds since [`Self::start_measurement`]
+    /// is enough for the current integration time to have settled.
+    pub fn is_ready(&self, elapsed_ms: i32) -> bool {
+        is_ready(elapsed_ms, self.integration_time)
+    }
 
-        // save sensitivity before potentially changing to another integration time
-        let sensitivity = self.integration_time.sensitivity();
+    /// Reads the channels after a triggered measurement has settled and
+    /// applies the auto-ranging adjustment, returning the scaled,
+    /// absolute measurement.
+    ///
+    /// Must only be called once [`Self::is_ready`] reports `true` for the
+    /// measurement started by [`Self::start_measurement`].
+    pub fn collect(&mut self) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
+        let reading = self.sensor.read_all_channels().map_err(error_mapper)?;
+        let (new_integration_time, result) =
+            ranging_decision(&reading, self.integration_time, &self.thresholds, &self.dark_offsets);
 
-        if let Some(new_integration_time) = new_integration_time_opt {
+        if new_integration_time != self.integration_time {
             self.integration_time = new_integration_time;
             debug!(target: "Wrapper", "Switching to integration time {:?}...", self.integration_time.millis());
             self.sensor.set_integration_time(self.integration_time).map_err(error_mapper)?;
         }
 
-        if green < DARK_THRESHOLD_HARD {
-            if new_integration_time_opt == None {
-                return Err(AbsoluteMeasurementError::TooDarkAbsolute);
-            } else {
-                return Err(AbsoluteMeasurementError::TooDarkRelative);
-            }
-        } else if green > BRIGHT_THRESHOLD_HARD {
-            if new_integration_time_opt == None {
-                return Err(AbsoluteMeasurementError::TooBrightAbsolute);
-            } else {
-                return Err(AbsoluteMeasurementError::TooBrightRelative);
-            }
-        } else {
-            return Ok({
-                AbsoluteMeasurementChannels {
-                    red:   sensitivity * (reading.red as f32),
-                    green: sensitivity * (green as f32),
-                    blue:  sensitivity * (reading.blue as f32),
-                    white: sensitivity * (reading.white as f32),
-                }
-            })
-        }
+        result
+    }
 
-     
+    /// Makes a single reading, which may either succeed or return an error.
+    /// If possible, the integration time is adjusted after the measuement,
+    /// so that future measurements may have more success then the current one.
+    ///
+    /// This blocks the current thread for the integration time's settling
+    /// period. On targets without `std`, drive [`Self::start_measurement`],
+    /// [`Self::is_ready`] and [`Self::collect`] directly instead.
+    #[cfg(feature = "std")]
+    pub fn read_absolute_once(&mut self) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
+        let wait_time = self.start_measurement()?;
+        std::thread::sleep(core::time::Duration::from_millis(wait_time as u64));
+        self.collect()
     }
 
     /// Make measuements, and retry as long as the integration time can be optimized to get a valid
     /// measuement. Will return either a valid, absolute measurement, or an error indicating the reason.
+    #[cfg(feature = "std")]
     pub fn read_absolute_retry(&mut self) -> Result<AbsoluteMeasurementChannels, AbsoluteMeasurementError<E>> {
         loop {
             let result = self.read_absolute_once();
@@ -143,4 +319,35 @@ where
         }
 
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_ready, reduce};
+    use crate::IntegrationTime;
+
+    #[test]
+    fn reduce_subtracts_the_offset() {
+        assert_eq!(reduce(500, 100), 400);
+    }
+
+    #[test]
+    fn reduce_saturates_at_zero_when_offset_exceeds_raw() {
+        assert_eq!(reduce(50, 100), 0);
+    }
+
+    #[test]
+    fn is_ready_false_before_the_waiting_time_has_elapsed() {
+        assert!(!is_ready(150, IntegrationTime::_160ms));
+    }
+
+    #[test]
+    fn is_ready_true_exactly_at_the_waiting_time() {
+        assert!(is_ready(200, IntegrationTime::_160ms));
+    }
+
+    #[test]
+    fn is_ready_true_past_the_waiting_time() {
+        assert!(is_ready(500, IntegrationTime::_160ms));
+    }
 }
\ No newline at end of file